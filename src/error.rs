@@ -0,0 +1,52 @@
+
+use std::fmt;
+use super::*;
+
+/// The reason a fallible insert via [`Registry::try_add`] failed.
+///
+/// `Registry::add` treats every one of these as a fatal startup error and
+/// panics, which is the right default for trusted config. When idents come
+/// from user mods or network data, `try_add` surfaces them as a `Result` so
+/// the caller can skip, rename, or report the bad entry instead of aborting.
+pub enum RegistryError<I: Any> {
+    /// An entry with the same identifier is already present.
+    Duplicate {
+        /// The `LocalKey` of the entry that already occupies this identifier.
+        existing: LocalKey<I>,
+    },
+    /// Two distinct identifiers hashed to the same 128-bit fingerprint.
+    HashCollision,
+    /// The `u16` index space is exhausted (at most 65535 entries).
+    CapacityExceeded,
+}
+
+impl<I: Any> fmt::Display for RegistryError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::Duplicate { .. } => {
+                write!(f, "an entry with this identifier already exists")
+            }
+            RegistryError::HashCollision => {
+                write!(f, "a different identifier hashes to the same fingerprint")
+            }
+            RegistryError::CapacityExceeded => {
+                write!(f, "the registry is full (maximum 65535 entries)")
+            }
+        }
+    }
+}
+
+impl<I: Any> fmt::Debug for RegistryError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::Duplicate { existing } => f
+                .debug_struct("Duplicate")
+                .field("existing", &existing.index)
+                .finish(),
+            RegistryError::HashCollision => write!(f, "HashCollision"),
+            RegistryError::CapacityExceeded => write!(f, "CapacityExceeded"),
+        }
+    }
+}
+
+impl<I: Any> std::error::Error for RegistryError<I> {}
@@ -4,17 +4,17 @@ use bevy::app::App;
 use crate::Registry;
 
 pub trait AppRegistryExt {
-    fn init_registry<I: Any>(&mut self) -> &mut Self;
-    fn insert_registry<I: Any>(&mut self, registry: Registry<I>) -> &mut Self;
+    fn init_registry<I: Any + Send + Sync>(&mut self) -> &mut Self;
+    fn insert_registry<I: Any + Send + Sync>(&mut self, registry: Registry<I>) -> &mut Self;
 }
 
 impl AppRegistryExt for App {
-    fn init_registry<I: Any>(&mut self) -> &mut Self {
+    fn init_registry<I: Any + Send + Sync>(&mut self) -> &mut Self {
         self.insert_resource(Registry::<I>::new());
         self
     }
 
-    fn insert_registry<I: Any>(&mut self, registry: Registry<I>) -> &mut Self {
+    fn insert_registry<I: Any + Send + Sync>(&mut self, registry: Registry<I>) -> &mut Self {
         self.insert_resource(registry);
         self
     }
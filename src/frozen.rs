@@ -0,0 +1,121 @@
+
+use bevy::prelude::Resource;
+use super::*;
+
+/// Number of entries per storage block. Blocks are allocated to exactly this
+/// capacity and never grown, so their backing buffer never reallocates and the
+/// address of every `Entry` is stable for the life of the [`FrozenRegistry`].
+const CHUNK_LEN: usize = 64;
+
+/// Stable, never-reallocated block storage for entries.
+///
+/// Entries live in fixed-capacity blocks rather than one contiguous `Vec`, so
+/// that references handed out to readers never dangle: a block is filled once
+/// and its buffer is never moved. Indexing mirrors the `LocalKey` index space,
+/// `block = index / CHUNK_LEN`, `offset = index % CHUNK_LEN`.
+struct Chunks<I: Any> {
+    blocks: Vec<Vec<Entry<I>>>,
+    len: usize,
+}
+
+impl<I: Any> Chunks<I> {
+    /// Pack a contiguous vector of entries into fixed-capacity blocks.
+    fn from_items(items: Vec<Entry<I>>) -> Self {
+        let len = items.len();
+        let mut blocks = Vec::with_capacity(len / CHUNK_LEN + 1);
+        let mut items = items.into_iter();
+        loop {
+            let mut block = Vec::with_capacity(CHUNK_LEN);
+            block.extend(items.by_ref().take(CHUNK_LEN));
+            if block.is_empty() {
+                break;
+            }
+            blocks.push(block);
+        }
+        Self { blocks, len }
+    }
+
+    fn get(&self, index: usize) -> &Entry<I> {
+        &self.blocks[index / CHUNK_LEN][index % CHUNK_LEN]
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &Entry<I>> {
+        self.blocks.iter().flat_map(|block| block.iter())
+    }
+}
+
+/// A read-only, `Sync` view of a `Registry` suitable for sharing across
+/// parallel Bevy systems.
+///
+/// Building a registry is confined to an exclusive `&mut` phase; once building
+/// is done, [`Registry::freeze`] yields a `FrozenRegistry`. Because a frozen
+/// registry never mutates, many systems can hold `&FrozenRegistry` and query
+/// it concurrently with no locking. Entries live in stable chunks so the
+/// references returned by `search`/indexing stay valid for the handle's life,
+/// and the lookup table is held behind an `Arc`. `FrozenRegistry` does not
+/// implement `Clone`.
+///
+/// As with the building `Registry`, its `Send`/`Sync` are derived soundly from
+/// `I` — there is no hand-written blanket `unsafe impl`.
+pub struct FrozenRegistry<I: Any> {
+    /// Entries in stable, never-reallocated blocks.
+    chunks: Chunks<I>,
+    /// The immutable lookup table for GlobalKey fingerprints.
+    table: Arc<InsertMap>,
+}
+
+impl<I: Any + Send + Sync> Resource for FrozenRegistry<I> {}
+
+impl<I: Any> FrozenRegistry<I> {
+    /// Search for an Entry by its GlobalKey. Lock-free.
+    pub fn search(&self, key: GlobalKey<I>) -> Option<&Entry<I>> {
+        self.table.get(key.hash).map(|idx| self.chunks.get(idx as usize))
+    }
+
+    /// Iterate immutably over the entries. Lock-free.
+    pub fn iter(&self) -> impl Iterator<Item = &Entry<I>> {
+        self.chunks.iter()
+    }
+
+    /// The number of entries in the registry.
+    pub fn len(&self) -> usize {
+        self.chunks.len
+    }
+
+    /// Whether the registry holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.chunks.len == 0
+    }
+}
+
+impl<I: Any> Index<LocalKey<I>> for FrozenRegistry<I> {
+    type Output = Entry<I>;
+
+    fn index(&self, key: LocalKey<I>) -> &Self::Output {
+        self.chunks.get(key.index as usize)
+    }
+}
+
+impl<'i, I: Any> IntoIterator for &'i FrozenRegistry<I> {
+    type IntoIter = std::iter::Flatten<std::slice::Iter<'i, Vec<Entry<I>>>>;
+    type Item = &'i Entry<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chunks.blocks.iter().flatten()
+    }
+}
+
+impl<I: Any> Registry<I> {
+    /// Finish building and produce a lock-free, `Sync` read handle.
+    ///
+    /// Entries are moved into stable chunk storage and the lookup table is
+    /// published behind an `Arc`. Tags are a build-time grouping and are not
+    /// carried into the frozen handle, which is dedicated to the hot
+    /// `search`/index/iter read path.
+    pub fn freeze(self) -> FrozenRegistry<I> {
+        FrozenRegistry {
+            chunks: Chunks::from_items(self.items),
+            table: Arc::new(self.table),
+        }
+    }
+}
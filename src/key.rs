@@ -1,7 +1,5 @@
 use std::{fmt, hash::Hash, marker::PhantomData};
 use serde::{de::Visitor, Deserialize, Serialize};
-use xxhash_rust::xxh64::xxh64;
-use super::HASH_SEED;
 
 use crate::*;
 
@@ -43,10 +41,10 @@ impl<I: Any> Eq for LocalKey<I> {}
 /// platforms, or runtimes. Thus, it is suitable for
 /// serializing/deserializing references to registry 
 /// entries or for sending entries over the network. 
-/// Lookup time is O(log n) because it traverses a binary tree.
+/// Lookup time is O(1) average because it probes an open-addressing table.
 /// GlobalKeys are created from a string Identifier
 /// or can be retrieved from an entry with `Entry::global_key()`.
-/// 
+///
 /// ```
 /// fn search(registry: Registry<i32>) {
 ///     if let Some(item) = registry.search(GlobalKey::new("my:ident")) {
@@ -54,19 +52,21 @@ impl<I: Any> Eq for LocalKey<I> {}
 ///     }
 /// }
 /// ```
-/// 
-/// GlobalKeys will Serialize as a u64 and Deserialize from
-/// either a u64 or hash a String into it. Because of this, the hash seed
-/// must be a constant and is not configurable.
+///
+/// The hash is a 128-bit fingerprint (two `xxh64` passes with distinct seeds)
+/// so accidental collisions are astronomically unlikely. GlobalKeys Serialize
+/// as a `[u64; 2]` and Deserialize from that pair, a bare `u128`, or a string
+/// identifier which is hashed into one. Because of this, the hash seeds must be
+/// constants and are not configurable.
 pub struct GlobalKey<I: Any> {
-    pub(in super) hash: u64,
+    pub(in super) hash: u128,
     pub(in super) marker: PhantomData<I>,
 }
 
 impl<I: Any> GlobalKey<I> {
     pub fn new(ident: &str) -> Self {
         Self {
-            hash: xxh64(ident.as_bytes(), HASH_SEED),
+            hash: fingerprint(ident),
             marker: PhantomData::<I>,
         }
     }
@@ -79,47 +79,54 @@ impl<'de, I: Any> Deserialize<'de> for GlobalKey<I> {
     {
         struct GlobalKeyVisitor;
         impl<'de> Visitor<'de> for GlobalKeyVisitor {
-            type Value = u64;
+            type Value = u128;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "A u64 hash or a string identifier.")
+                write!(formatter, "A [u64; 2] fingerprint, a u128 hash, or a string identifier.")
             }
 
-            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
             where
-                E: serde::de::Error, 
+                E: serde::de::Error,
             {
-                Ok(v)    
+                Ok(v)
             }
 
-            fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
             where
-                E: serde::de::Error, 
+                E: serde::de::Error,
             {
-                Ok(v as u64)    
+                Ok(v as u128)
             }
 
-            fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
-                E: serde::de::Error, 
+                E: serde::de::Error,
             {
-                Ok(v as u64)    
+                Ok(fingerprint(v))
             }
 
-            fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
             where
-                E: serde::de::Error, 
+                E: serde::de::Error,
             {
-                Ok(v as u64)    
+                Ok(fingerprint(&v))
             }
 
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
             where
-                E: serde::de::Error, 
+                A: serde::de::SeqAccess<'de>,
             {
-                    Ok(xxh64(v.as_bytes(), HASH_SEED))
+                use serde::de::Error;
+                let hi: u64 = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let lo: u64 = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                Ok(((hi as u128) << 64) | lo as u128)
             }
-        } 
+        }
 
         Ok(
             Self {
@@ -133,9 +140,10 @@ impl<'de, I: Any> Deserialize<'de> for GlobalKey<I> {
 impl<I: Any> Serialize for GlobalKey<I> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
-            S: serde::Serializer 
+            S: serde::Serializer
     {
-        serializer.serialize_u64(self.hash)    
+        let fingerprint = [(self.hash >> 64) as u64, self.hash as u64];
+        fingerprint.serialize(serializer)
     }
 }
 
@@ -151,7 +159,7 @@ impl<I: Any> Clone for GlobalKey<I> {
 
 impl<I: Any> Hash for GlobalKey<I> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        state.write_u64(self.hash)
+        state.write_u128(self.hash)
     }
 }
 
@@ -8,18 +8,40 @@ mod key;
 mod entry;
 mod map;
 mod ext;
+mod tag;
+mod snapshot;
+mod error;
+mod frozen;
 
 pub use key::{GlobalKey, LocalKey};
 pub use entry::Entry;
+pub use error::RegistryError;
+pub use frozen::FrozenRegistry;
 
 pub mod prelude {
     pub use super::key::{GlobalKey, LocalKey};
     pub use super::entry::Entry;
     pub use super::Registry;
+    pub use super::error::RegistryError;
+    pub use super::frozen::FrozenRegistry;
     pub use super::ext::AppRegistryExt;
 }
 
 const HASH_SEED: u64 = 123456789123456789;
+const HASH_SEED_2: u64 = 987654321987654321;
+
+/// Compute the 128-bit fingerprint of an identifier.
+///
+/// Two `xxh64` passes with distinct seeds are concatenated into a `u128`. A
+/// 64-bit hash has a non-negligible birthday-collision probability once a
+/// registry holds thousands of entries; widening to 128 bits makes accidental
+/// collisions astronomically unlikely, turning the fatal-collision branch into
+/// effectively dead code for real workloads.
+pub(crate) fn fingerprint(ident: &str) -> u128 {
+    let lo = xxh64(ident.as_bytes(), HASH_SEED);
+    let hi = xxh64(ident.as_bytes(), HASH_SEED_2);
+    ((hi as u128) << 64) | lo as u128
+}
 
 /// An insert-only container for storing and accessing a type.
 /// When an entry is added to a Registry, a `GlobalKey` and a `LocalKey` are created for it.
@@ -50,12 +72,13 @@ const HASH_SEED: u64 = 123456789123456789;
 ///     reg.search(key)
 /// }
 /// ```
-#[derive(Resource)]
 pub struct Registry<I: Any> {
     /// An Insert-only Vector for storage.
     items: Vec<Entry<I>>,
     /// Lookup table for GlobalKey hashes.
     table: InsertMap,
+    /// Named tags grouping subsets of entries.
+    tags: tag::TagStore<I>,
 }
 
 impl<I: Any> Registry<I> {
@@ -63,7 +86,8 @@ impl<I: Any> Registry<I> {
     pub fn new() -> Self {
         Self {
             items: Vec::with_capacity(64),
-            table: InsertMap { entries: Vec::with_capacity(64) },
+            table: InsertMap::with_capacity(64),
+            tags: tag::TagStore::new(),
         }
     }
 
@@ -71,7 +95,8 @@ impl<I: Any> Registry<I> {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             items: Vec::with_capacity(capacity),
-            table: InsertMap { entries: Vec::with_capacity(capacity) },
+            table: InsertMap::with_capacity(capacity),
+            tags: tag::TagStore::new(),
         }
     }
 
@@ -81,54 +106,90 @@ impl<I: Any> Registry<I> {
     }
 
     /// Insert a new entry into the Registry.
+    ///
+    /// Panics on a fatal config error (duplicate/colliding ident or a full
+    /// registry). It shares its insert logic with [`Registry::try_add`] and is
+    /// for callers whose idents are trusted and whose errors are unrecoverable.
     pub fn add(&mut self, ident: &str, item: I) -> &Entry<I> {
-        // registries must not be greater than 65535
-        // because the local key is a u16 index.
-        if self.items.len() >= u16::MAX as usize {
-            let header = "!! FATAL REGISTRY ERROR !!".red();
-            let s = ">".red();
-            let ident = ident.magenta();
-            let _type = std::any::type_name::<I>().magenta();
-            panic!("
+        match self.insert_entry(ident, item) {
+            Ok(local) => &self.items[local as usize],
+            Err(RegistryError::CapacityExceeded) => {
+                // registries must not be greater than 65535
+                // because the local key is a u16 index.
+                let header = "!! FATAL REGISTRY ERROR !!".red();
+                let s = ">".red();
+                let ident = ident.magenta();
+                let _type = std::any::type_name::<I>().magenta();
+                panic!("
 # {header}
 # {s} Attempted to insert an item '{ident}' into registry of type '{_type}'.
 # {s} However, the entries buffer in the registry is full. (len=65535)
 # {s} This limitation exists because LocalKeys are an index in the buffer and are 16 bits.
-# {s} If you need more entries, you will need to use another library. 
-            ")
-        }
-
-        // get the hash value and index. 
-        let global = xxh64(ident.as_bytes(), HASH_SEED);
-        let local = self.items.len() as u16;
-
-        // Insert the global key into its table.
-        if let Some(collision) = self.table.insert(global, local) {
-            // if insertion into the hash table fails, there
-            // has either been a collision (very unlikely), or
-            // the same value was inserted twice. 
-            let _type = std::any::type_name::<I>().magenta();
-            let other = &*self.items[collision as usize].ident().magenta();
-            let ident = ident.magenta();
-            let header = "!! FATAL REGISTRY ERROR !!".red();
-            let fixes = "> Possible Fixes".cyan();
-            let s = ">".red();
-            panic!("
+# {s} If you need more entries, you will need to use another library.
+                ")
+            }
+            Err(_) => {
+                // if insertion into the hash table fails, there
+                // has either been a collision (very unlikely), or
+                // the same value was inserted twice.
+                let other_idx = self.table.get(fingerprint(ident)).unwrap() as usize;
+                let _type = std::any::type_name::<I>().magenta();
+                let other = &*self.items[other_idx].ident().magenta();
+                let ident = ident.magenta();
+                let header = "!! FATAL REGISTRY ERROR !!".red();
+                let fixes = "> Possible Fixes".cyan();
+                let s = ">".red();
+                panic!("
 # {header}
 # {s} Attempted to insert an item '{ident}' into registry of type '{_type}'.
 # {s} However, another item with the ident '{other}' has the same hash.
-# {s} Registries require that every entry have a unique identifier. 
+# {s} Registries require that every entry have a unique identifier.
 # {s} This error only occurs under two conditions:
-#    1. Two identifiers hash to the same u64 (collision).
+#    1. Two identifiers hash to the same u128 (collision).
 #    2. Two entries have the same identifier (duplication).
 # {fixes}:
 #    1. Validate your code to ensure there is no duplication.
 #    2. Namespace your identifiers. (e.g. 'my_ns:ident').
-#    3. Change the name of one of the entries. 
-            ");
+#    3. Change the name of one of the entries.
+                ");
+            }
         }
+    }
 
-        // insert the item into the Vec. 
+    /// Fallibly insert a new entry into the Registry.
+    ///
+    /// Unlike [`Registry::add`], this returns a [`RegistryError`] instead of
+    /// panicking, so callers loading untrusted data (user mods, network
+    /// payloads) can recover gracefully.
+    pub fn try_add(&mut self, ident: &str, item: I) -> Result<&Entry<I>, RegistryError<I>> {
+        let local = self.insert_entry(ident, item)?;
+        Ok(&self.items[local as usize])
+    }
+
+    /// Core insert logic shared by [`add`](Registry::add) and
+    /// [`try_add`](Registry::try_add). Returns the new entry's local index, or
+    /// the reason the insert could not happen. No partial state is left behind
+    /// on error.
+    fn insert_entry(&mut self, ident: &str, item: I) -> Result<u16, RegistryError<I>> {
+        if self.items.len() >= u16::MAX as usize {
+            return Err(RegistryError::CapacityExceeded);
+        }
+
+        // get the hash value and index.
+        let global = fingerprint(ident);
+        let local = self.items.len() as u16;
+
+        // Insert the global key into its table.
+        if let Some(collision) = self.table.insert(global, local) {
+            let existing = self.items[collision as usize].local_key();
+            return if self.items[collision as usize].ident() == ident {
+                Err(RegistryError::Duplicate { existing })
+            } else {
+                Err(RegistryError::HashCollision)
+            };
+        }
+
+        // insert the item into the Vec.
         self.items.push(
             Entry {
                 ident: Arc::from(ident),
@@ -138,7 +199,7 @@ impl<I: Any> Registry<I> {
             }
         );
 
-        return &self.items[local as usize]
+        Ok(local)
     }
 
     /// Search the for an Entry by its GlobalKey. 
@@ -194,8 +255,10 @@ impl<'i, I: Any> IntoIterator for &'i mut Registry<I> {
     }
 }
 
-unsafe impl<I: Any> Send for Registry<I> {}
-unsafe impl<I: Any> Sync for Registry<I> {}
+// A Registry is a Bevy Resource only when its item type is itself thread-safe.
+// Send/Sync are derived soundly from `I` rather than asserted unconditionally,
+// so it is never possible to share a registry holding a non-thread-safe item.
+impl<I: Any + Send + Sync> Resource for Registry<I> {}
 
 #[cfg(test)]
 mod tests {
@@ -255,4 +318,62 @@ mod tests {
 
         assert!(reg[local].ident() == "item:f")
     }
+
+    #[test]
+    fn try_add_duplicate() {
+        use crate::RegistryError;
+
+        let mut reg = Registry::<i32>::new();
+        let first = reg.add("item:a", 0).local_key();
+
+        match reg.try_add("item:a", 1) {
+            Err(RegistryError::Duplicate { existing }) => assert!(existing == first),
+            Err(e) => panic!("expected Duplicate, got {e:?}"),
+            Ok(_) => panic!("expected Duplicate, got Ok"),
+        }
+
+        // the failed insert must not have changed the registry.
+        assert_eq!(reg.iter().count(), 1);
+        assert!(reg.try_add("item:b", 2).is_ok());
+    }
+
+    #[test]
+    fn freeze_reads() {
+        let mut reg = Registry::<i32>::new();
+        for i in 0..100 {
+            reg.add(&format!("item:{i}"), i);
+        }
+
+        let frozen = reg.freeze();
+        assert_eq!(frozen.len(), 100);
+
+        let entry = frozen.search(GlobalKey::new("item:42")).unwrap();
+        assert_eq!(entry.item, 42);
+        assert_eq!(frozen[entry.local_key()].ident(), "item:42");
+        assert_eq!(frozen.iter().count(), 100);
+    }
+
+    #[test]
+    fn tags() {
+        let mut reg = Registry::<i32>::new();
+        reg.add("log:oak", 0);
+        reg.add("log:birch", 1);
+        reg.add("ore:iron", 2);
+
+        reg.add_tag("group:logs", &[
+            GlobalKey::new("log:oak"),
+            GlobalKey::new("log:birch"),
+            // a member that is never inserted is skipped on resolution.
+            GlobalKey::new("log:spruce"),
+        ]);
+
+        let logs = reg.tag("group:logs").unwrap();
+        assert_eq!(logs.len(), 2);
+        assert!(logs.iter().all(|&k| reg[k].ident().starts_with("log:")));
+
+        assert!(reg.tag("group:ores").is_none());
+
+        let oak = GlobalKey::new("log:oak");
+        assert!(reg.tags_of(oak).eq(["group:logs"]));
+    }
 }
\ No newline at end of file
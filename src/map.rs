@@ -1,57 +1,115 @@
-use std::cmp::Ordering;
-
-pub(super) struct Node {
-    key: u64,
-    val: u16,
-    gt: Option<u16>,
-    lt: Option<u16>,
-}
-
-/// An Insert-only densely packed map. 
+/// An Insert-only densely packed map.
 /// I'm using this to minimize allocations
 /// during program startup. BTreeMap heap allocates
 /// for every element. We don't need fast removal
-/// so we don't care. 
+/// so we don't care.
+///
+/// Keys are already hashes, so rather than hash them again this is an
+/// open-addressing table in the style of hashbrown/SwissTable: a power-of-two
+/// array of control bytes sits beside a parallel array of `(key, val)` slots.
+/// Each key splits into `h1 = key >> 7` (the start bucket) and `h2 = key & 0x7f`
+/// (the control byte). Probing compares the one-byte `h2` first and only reads
+/// the full key on a byte match, so the common case touches a single cache
+/// line. The table stays insert-only (no removal) and still reports the
+/// colliding index on a duplicate key so the fatal-collision diagnostics keep
+/// working.
 pub struct InsertMap {
-    pub(super) entries: Vec<Node>,
+    /// Control bytes: `h2` for a full slot, or `EMPTY` for an open one.
+    ctrl: Vec<u8>,
+    /// Parallel `(key, val)` slots, meaningful only where `ctrl` is not `EMPTY`.
+    slots: Vec<(u128, u16)>,
+    /// Number of occupied slots.
+    len: usize,
 }
 
+/// Control byte marking an empty slot. The top bit is reserved for this, so a
+/// real `h2` (the low 7 bits of a key) can never be mistaken for `EMPTY`.
+const EMPTY: u8 = 0x80;
+
+/// Smallest table capacity. Must be a power of two.
+const MIN_CAP: usize = 16;
+
 impl InsertMap {
-    pub fn insert(&mut self, key: u64, val: u16) -> Option<u16> {
-        let index = self.entries.len();
-        if index == 0 {
-            self.entries.push(Node {
-                key, val, gt: None, lt: None
-            });
-            return None;
+    /// Construct an empty map with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(MIN_CAP)
+    }
+
+    /// Construct an empty map with room for roughly `capacity` keys before the
+    /// first rehash.
+    pub fn with_capacity(capacity: usize) -> Self {
+        // Leave headroom for the 87.5% load factor and round up to a power of two.
+        let cap = round_up_pow2(capacity + capacity / 7 + 1);
+        Self {
+            ctrl: vec![EMPTY; cap],
+            slots: vec![(0, 0); cap],
+            len: 0,
         }
+    }
 
-        let mut i = 0;
+    /// Insert `key` -> `val`. Returns `None` on success, or `Some(existing)`
+    /// with the value already stored for `key` (the insert is a no-op then).
+    pub fn insert(&mut self, key: u128, val: u16) -> Option<u16> {
+        // Grow before we would exceed ~87.5% load.
+        if (self.len + 1) * 8 > self.ctrl.len() * 7 {
+            self.grow();
+        }
+
+        let mask = self.ctrl.len() - 1;
+        let h2 = (key & 0x7f) as u8;
+        let mut i = (key >> 7) as usize & mask;
+        let mut stride = 1;
         loop {
-            let next = match key.cmp(&self.entries[i].key) {
-                Ordering::Greater => &mut self.entries[i].gt,
-                Ordering::Less => &mut self.entries[i].lt,
-                Ordering::Equal => return Some(i as u16),
-            };
-
-            if next.is_none() {
-                *next = Some(index as u16);
-                self.entries.push(Node { key, val, gt: None, lt: None });
+            if self.ctrl[i] == EMPTY {
+                self.ctrl[i] = h2;
+                self.slots[i] = (key, val);
+                self.len += 1;
                 return None;
-            } else {
-                i = next.unwrap() as usize;
+            } else if self.ctrl[i] == h2 && self.slots[i].0 == key {
+                return Some(self.slots[i].1);
             }
+            i = (i + stride) & mask;
+            stride += 1;
         }
     }
 
-    pub fn get(&self, key: u64) -> Option<u16> {
-        let mut i = 0;
+    /// Look up the value stored for `key`, if any.
+    pub fn get(&self, key: u128) -> Option<u16> {
+        let mask = self.ctrl.len() - 1;
+        let h2 = (key & 0x7f) as u8;
+        let mut i = (key >> 7) as usize & mask;
+        let mut stride = 1;
         loop {
-            i = match key.cmp(&self.entries[i].key) {
-                Ordering::Greater => self.entries[i].gt?.into(),
-                Ordering::Less => self.entries[i].lt?.into(),
-                Ordering::Equal => return Some(self.entries[i].val),
-            };
+            if self.ctrl[i] == EMPTY {
+                return None;
+            } else if self.ctrl[i] == h2 && self.slots[i].0 == key {
+                return Some(self.slots[i].1);
+            }
+            i = (i + stride) & mask;
+            stride += 1;
+        }
+    }
+
+    /// Double the capacity and rehash every occupied slot.
+    fn grow(&mut self) {
+        let old_ctrl = std::mem::take(&mut self.ctrl);
+        let old_slots = std::mem::take(&mut self.slots);
+        let new_cap = old_ctrl.len() * 2;
+
+        self.ctrl = vec![EMPTY; new_cap];
+        self.slots = vec![(0, 0); new_cap];
+        self.len = 0;
+
+        for (i, &ctrl) in old_ctrl.iter().enumerate() {
+            if ctrl != EMPTY {
+                let (key, val) = old_slots[i];
+                self.insert(key, val);
+            }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Round `n` up to the next power of two, never below [`MIN_CAP`].
+fn round_up_pow2(n: usize) -> usize {
+    n.max(MIN_CAP).next_power_of_two()
+}
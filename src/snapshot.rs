@@ -0,0 +1,143 @@
+
+use serde::de::Error as _;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use super::*;
+
+/// The on-the-wire format version for a whole-registry snapshot. Bumped when
+/// the snapshot layout changes so that receivers can reject payloads they do
+/// not understand.
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// A whole `Registry` serializes as a versioned snapshot: a format version
+/// plus every entry as `{ ident, item }`. Entries are emitted in local order,
+/// but because each is keyed by its string identifier a receiver rebuilds
+/// identical `GlobalKey`s regardless of its own insertion order.
+impl<I: Any + Serialize> Serialize for Registry<I> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut snapshot = serializer.serialize_struct("Registry", 2)?;
+        snapshot.serialize_field("version", &SNAPSHOT_VERSION)?;
+        snapshot.serialize_field("entries", &Entries(self))?;
+        snapshot.end()
+    }
+}
+
+/// Serializes the registry's entries as a sequence of `{ ident, item }`.
+struct Entries<'a, I: Any>(&'a Registry<I>);
+
+impl<I: Any + Serialize> Serialize for Entries<'_, I> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.items.len()))?;
+        for entry in &self.0.items {
+            seq.serialize_element(&EntryRef {
+                ident: &entry.ident,
+                item: &entry.item,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+/// A borrowed view of a single entry for serialization.
+struct EntryRef<'a, I> {
+    ident: &'a str,
+    item: &'a I,
+}
+
+impl<I: Serialize> Serialize for EntryRef<'_, I> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut entry = serializer.serialize_struct("Entry", 2)?;
+        entry.serialize_field("ident", self.ident)?;
+        entry.serialize_field("item", self.item)?;
+        entry.end()
+    }
+}
+
+/// The owned shape a snapshot deserializes into before it is replayed into a
+/// registry by re-inserting each entry by its identifier.
+#[derive(Deserialize)]
+struct RegistrySnapshot<I> {
+    version: u32,
+    entries: Vec<EntrySnapshot<I>>,
+}
+
+#[derive(Deserialize)]
+struct EntrySnapshot<I> {
+    ident: String,
+    item: I,
+}
+
+impl<I: Any> Registry<I> {
+    /// Rebuild a `Registry` from a snapshot produced by [`Serialize`].
+    ///
+    /// Entries are re-inserted by ident, so the receiver reconstructs the same
+    /// `GlobalKey`s as the sender while assigning its own platform-local
+    /// `LocalKey`s.
+    pub fn from_snapshot<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+        I: Deserialize<'de>,
+    {
+        let mut registry = Self::new();
+        registry.deserialize_into(deserializer)?;
+        Ok(registry)
+    }
+
+    /// Replay a snapshot into this registry, re-inserting every entry by ident.
+    pub fn deserialize_into<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+        I: Deserialize<'de>,
+    {
+        let snapshot = RegistrySnapshot::<I>::deserialize(deserializer)?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(D::Error::custom(format!(
+                "unsupported registry snapshot version {} (expected {})",
+                snapshot.version, SNAPSHOT_VERSION
+            )));
+        }
+
+        self.reserve(snapshot.entries.len());
+        for entry in snapshot.entries {
+            // Snapshots may arrive from the network or untrusted save files, so
+            // a duplicate/colliding ident or capacity overflow must be reported
+            // rather than aborting the receiver.
+            self.try_add(&entry.ident, entry.item).map_err(|e| {
+                D::Error::custom(format!("invalid registry snapshot: {e}"))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// The `GlobalKey`s of every entry, in local-index order.
+    ///
+    /// A sender ships this table alongside a `LocalKey`-based payload so the
+    /// receiver can [`remap`](Registry::remap) those indices onto its own
+    /// `LocalKey`s.
+    pub fn global_keys(&self) -> Vec<GlobalKey<I>> {
+        self.items.iter().map(Entry::global_key).collect()
+    }
+
+    /// Translate a foreign `LocalKey` onto this registry.
+    ///
+    /// `sender` is the sender's [`global_keys`](Registry::global_keys) table:
+    /// the foreign index selects the `GlobalKey` it referred to, which is then
+    /// resolved against this registry. Returns `None` if the index is out of
+    /// range or the referenced entry is not resident here. This is what lets a
+    /// `LocalKey`-based payload survive the trip despite insertion-order
+    /// differences across runtimes.
+    pub fn remap(&self, foreign: LocalKey<I>, sender: &[GlobalKey<I>]) -> Option<LocalKey<I>> {
+        let global = *sender.get(foreign.index as usize)?;
+        self.search(global).map(Entry::local_key)
+    }
+}
@@ -0,0 +1,118 @@
+
+use std::sync::OnceLock;
+use super::*;
+
+/// Storage for named tags alongside a Registry's `items`/`table`.
+///
+/// A tag groups an arbitrary subset of entries under a single identifier
+/// (e.g. `"tools:pickaxes"`) so data authors can refer to "all logs" or
+/// "all ores" without enumerating members at every use site.
+///
+/// Tags are stored as the `GlobalKey`s of their members and are resolved
+/// lazily to the resident `LocalKey`s the first time they are queried,
+/// skipping any member that is not present in the registry. This deferred
+/// resolution lets tags reference entries that are added after the tag
+/// itself is declared — but resolution *freezes on first read*: the set of
+/// resident members is cached the first time a tag is queried, so add all of
+/// a tag's members before querying it. Declaring more members with
+/// [`Registry::add_tag`] clears the cache and re-opens resolution.
+///
+/// The cache is a [`OnceLock`], so concurrent queries from parallel systems
+/// resolve a tag exactly once without racing.
+pub(super) struct TagStore<I: Any> {
+    /// The defined tags, in declaration order.
+    tags: Vec<Tag<I>>,
+    /// Lookup table mapping a hashed tag identifier to its index in `tags`.
+    index: InsertMap,
+}
+
+/// A single tag definition and its (lazily resolved) membership.
+struct Tag<I: Any> {
+    /// The string identifier of the tag.
+    ident: Arc<str>,
+    /// The members of the tag, as the GlobalKeys they were declared with.
+    members: Vec<GlobalKey<I>>,
+    /// The resident members, resolved on first query.
+    resolved: OnceLock<Vec<LocalKey<I>>>,
+}
+
+impl<I: Any> TagStore<I> {
+    pub(super) fn new() -> Self {
+        Self {
+            tags: Vec::new(),
+            index: InsertMap::new(),
+        }
+    }
+}
+
+impl<I: Any> Registry<I> {
+    /// The key a tag is addressed by. A tag is hashed exactly like an entry
+    /// identifier, so tag references serialize the same way a `GlobalKey` does.
+    pub fn tag_key(tag: &str) -> GlobalKey<I> {
+        GlobalKey::new(tag)
+    }
+
+    /// Declare a tag grouping the given members.
+    ///
+    /// Members are recorded by `GlobalKey`; they do not need to be resident
+    /// yet. Declaring the same tag twice appends to its membership. The tag is
+    /// not resolved until it is first queried with [`Registry::tag`].
+    pub fn add_tag(&mut self, tag: &str, members: &[GlobalKey<I>]) {
+        let hash = fingerprint(tag);
+        let next = self.tags.tags.len() as u16;
+        if let Some(existing) = self.tags.index.insert(hash, next) {
+            // The tag already exists; extend it and invalidate any prior
+            // resolution so the new members are picked up.
+            let tag = &mut self.tags.tags[existing as usize];
+            tag.members.extend_from_slice(members);
+            tag.resolved = OnceLock::new();
+        } else {
+            self.tags.tags.push(Tag {
+                ident: Arc::from(tag),
+                members: members.to_vec(),
+                resolved: OnceLock::new(),
+            });
+        }
+    }
+
+    /// Resolve a tag to its resident entries, skipping members that are not
+    /// present in the registry. Returns `None` if the tag was never declared.
+    pub fn tag(&self, tag: &str) -> Option<&[LocalKey<I>]> {
+        self.tag_by_key(Self::tag_key(tag))
+    }
+
+    /// Resolve a tag by its hashed key. See [`Registry::tag`].
+    pub fn tag_by_key(&self, key: GlobalKey<I>) -> Option<&[LocalKey<I>]> {
+        if self.tags.tags.is_empty() {
+            return None;
+        }
+
+        let idx = self.tags.index.get(key.hash)? as usize;
+        let tag = &self.tags.tags[idx];
+        let resolved = tag.resolved.get_or_init(|| {
+            tag.members
+                .iter()
+                .filter_map(|member| {
+                    self.table.get(member.hash).map(|index| LocalKey {
+                        index,
+                        marker: PhantomData,
+                    })
+                })
+                .collect()
+        });
+
+        Some(resolved.as_slice())
+    }
+
+    /// Iterate over the identifiers of every tag the given entry belongs to.
+    ///
+    /// This is a reverse lookup over tag *membership*, so it reports tags even
+    /// for members that are not (yet) resident.
+    pub fn tags_of(&self, key: GlobalKey<I>) -> impl Iterator<Item = &str> {
+        self.tags
+            .tags
+            .iter()
+            .filter(move |tag| tag.members.iter().any(|member| member.hash == key.hash))
+            .map(|tag| tag.ident.as_ref())
+    }
+}